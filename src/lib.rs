@@ -1,3 +1,28 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "unboxed_closures", feature(unboxed_closures, fn_traits, tuple_trait))]
+// the whole point of the build_n_args*! macros is generating one impl
+// per arity up to 10, which trips clippy's arg-count heuristic by design
+#![allow(clippy::too_many_arguments)]
+// pre-existing style from when this crate predated clippy/a manifest:
+// tab-indented doc comments and bare (edition-2015-style) trait objects
+// throughout; not worth a drive-by reformat of unrelated code here
+#![allow(clippy::tabs_in_doc_comments)]
+#![allow(bare_trait_objects)]
+// the arg4 tests intentionally drop every argument (including
+// non-Drop marker structs) to exercise that they're all consumed
+#![allow(clippy::drop_non_drop)]
+
+// with the default `std` feature disabled, `Box` comes from `alloc`
+// instead of the prelude; pulling it in here makes the rest of the
+// crate (and the `build_n_args*!` macros) work unchanged either way.
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
+#[macro_use]
+mod macros;
+
 /// `BoxFnOnce` boxes any `FnOnce` function up to a certain number of
 /// arguments (10 as of now).
 ///
@@ -18,6 +43,23 @@
 /// type (like `BoxFnOnce<(), !>`) is not possible as the `!` type is
 /// experimental.
 ///
+/// Stable rust (1.35+) made `Box<FnOnce(Args...) -> Result>` callable
+/// directly, so `BoxFnOnce` now interoperates with it: it can be built
+/// `from` such a box (through the regular `From<F>` impl, since a boxed
+/// `FnOnce` is itself a `FnOnce`), and `into_boxed` converts it back.
+///
+/// With the (unstable, nightly-only) `unboxed_closures` cargo feature
+/// enabled, `BoxFnOnce` itself implements `FnOnce`, so it can be called
+/// with plain `f(a, b)` syntax and passed anywhere a `FnOnce(A, B) ->
+/// Result` is expected. That impl would conflict with the `From<F>`
+/// impls above (they'd overlap with the standard library's reflexive
+/// `impl<T> From<T> for T`), so with the feature enabled, use
+/// `BoxFnOnce::from_fn` instead of `BoxFnOnce::from`/`BoxFnOnce::new`.
+///
+/// The `std` cargo feature is on by default; disable it (`--no-default-
+/// features`) to use the crate in `#![no_std]` environments (embedded,
+/// kernels) that still have `alloc` available.
+///
 /// # Examples
 ///
 /// Move value into closure and box it:
@@ -25,10 +67,14 @@
 /// ```
 /// use boxfnonce::BoxFnOnce;
 /// let s = String::from("foo");
-/// let f : BoxFnOnce<()> = BoxFnOnce::from(|| {
+/// let func = || {
 ///     println!("Got called: {}", s);
 ///     drop(s);
-/// });
+/// };
+/// #[cfg(not(feature = "unboxed_closures"))]
+/// let f : BoxFnOnce<()> = BoxFnOnce::from(func);
+/// #[cfg(feature = "unboxed_closures")]
+/// let f : BoxFnOnce<()> = BoxFnOnce::<()>::from_fn(func);
 /// f.call();
 /// ```
 ///
@@ -37,10 +83,14 @@
 /// ```
 /// use boxfnonce::BoxFnOnce;
 /// let s = String::from("foo");
-/// let f : BoxFnOnce<(), String> = BoxFnOnce::from(|| {
+/// let func = || {
 ///     println!("Got called: {}", s);
 ///     s
-/// });
+/// };
+/// #[cfg(not(feature = "unboxed_closures"))]
+/// let f : BoxFnOnce<(), String> = BoxFnOnce::from(func);
+/// #[cfg(feature = "unboxed_closures")]
+/// let f : BoxFnOnce<(), String> = BoxFnOnce::<(), String>::from_fn(func);
 /// assert_eq!(f.call(), "foo".to_string());
 /// ```
 pub struct BoxFnOnce<Args, Result = ()> {
@@ -60,6 +110,7 @@ impl<Args, Result> BoxFnOnce<Args, Result> {
 	}
 
 	/// `BoxFnOnce::new` is an alias for `BoxFnOnce::from`.
+	#[cfg(not(feature = "unboxed_closures"))]
 	pub fn new<F>(func: F) -> Self
 		where Self: From<F>
 	{
@@ -77,6 +128,15 @@ impl<Result> BoxFnOnce<(), Result> {
 	}
 }
 
+impl<Result: 'static> BoxFnOnce<(), Result> {
+	/// convert into a boxed `FnOnce`, as supported by stable rust
+	/// since 1.35
+	pub fn into_boxed(self) -> Box<FnOnce() -> Result> {
+		Box::new(move || self.call())
+	}
+}
+
+#[cfg(not(feature = "unboxed_closures"))]
 impl<Result, F: 'static + FnOnce() -> Result> From<F> for BoxFnOnce<(), Result> {
 	fn from(func: F) -> Self {
 		let mut func = Some(func);
@@ -96,6 +156,24 @@ impl<Result, F: 'static + FnOnce() -> Result> From<F> for BoxFnOnce<(), Result>
 	}
 }
 
+// with `unboxed_closures` enabled, `BoxFnOnce` implements `FnOnce`
+// itself, so a blanket `From<F: FnOnce(...) -> Result>` impl would
+// conflict with the standard `impl<T> From<T> for T`; fall back to a
+// plain constructor instead.
+#[cfg(feature = "unboxed_closures")]
+impl<Result> BoxFnOnce<(), Result> {
+	/// build from a closure or function (see the crate-level docs for
+	/// why this isn't `From` while `unboxed_closures` is enabled)
+	pub fn from_fn<F: 'static + FnOnce() -> Result>(func: F) -> Self {
+		let mut func = Some(func);
+		BoxFnOnce{
+			func: Box::new(move |_| -> Result {
+				func.take().unwrap()()
+			})
+		}
+	}
+}
+
 macro_rules! build_n_args {
 	( $($var:ident: $typevar:ident),* ) => (
 		impl< $($typevar),*, Result> BoxFnOnce<($($typevar),*,), Result> {
@@ -107,6 +185,15 @@ macro_rules! build_n_args {
 			}
 		}
 
+		impl< $($typevar: 'static),*, Result: 'static> BoxFnOnce<($($typevar),*,), Result> {
+			/// convert into a boxed `FnOnce`, as supported by stable
+			/// rust since 1.35
+			pub fn into_boxed(self) -> Box<FnOnce($($typevar),*) -> Result> {
+				Box::new(move |$($var),*| self.call($($var),*))
+			}
+		}
+
+		#[cfg(not(feature = "unboxed_closures"))]
 		impl< $($typevar),*, Result, F: 'static + FnOnce($($typevar),*) -> Result> From<F> for BoxFnOnce<($($typevar),*,), Result> {
 			fn from(func: F) -> Self {
 				let mut func = Some(func);
@@ -125,6 +212,22 @@ macro_rules! build_n_args {
 				}
 			}
 		}
+
+		// see the zero-argument `from_fn` above for why this isn't `From`
+		#[cfg(feature = "unboxed_closures")]
+		impl< $($typevar),*, Result> BoxFnOnce<($($typevar),*,), Result> {
+			/// build from a closure or function (see the crate-level
+			/// docs for why this isn't `From` while `unboxed_closures`
+			/// is enabled)
+			pub fn from_fn<F: 'static + FnOnce($($typevar),*) -> Result>(func: F) -> Self {
+				let mut func = Some(func);
+				BoxFnOnce{
+					func: Box::new(move |($($var),*,)| -> Result {
+						func.take().unwrap()($($var),*)
+					})
+				}
+			}
+		}
 	)
 }
 
@@ -139,9 +242,358 @@ build_n_args!(a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8);
 build_n_args!(a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9);
 build_n_args!(a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9, a10: A10);
 
-#[cfg(test)]
+/// With the (unstable) `unboxed_closures` feature enabled, `BoxFnOnce`
+/// implements the real `FnOnce` trait, so it can be called with
+/// ordinary `f(a, b)` syntax and passed anywhere a `FnOnce(A, B) ->
+/// Result` is expected.
+///
+/// `Args` is always a tuple here (as everywhere else in this crate),
+/// so a single impl bounded on `Tuple` covers every arity generated by
+/// `build_n_args!` above.
+#[cfg(feature = "unboxed_closures")]
+impl<Args: core::marker::Tuple, Result> FnOnce<Args> for BoxFnOnce<Args, Result> {
+	type Output = Result;
+
+	extern "rust-call" fn call_once(self, args: Args) -> Result {
+		self.call_tuple(args)
+	}
+}
+
+/// Like `BoxFnOnce`, but the boxed closure is also required to be
+/// `Send`, so the whole box can be sent across threads.
+pub struct SendBoxFnOnce<Args, Result = ()> {
+	func: Box<FnMut(Args) -> Result + Send>,
+}
+
+impl<Args, Result> SendBoxFnOnce<Args, Result> {
+	/// call inner function, consumes the box.
+	///
+	/// `call_tuple` can be used if the arguments are available as tuple.
+	/// Each usable instance of SendBoxFnOnce<(...), Result> has a
+	/// separate `call` method for passing arguments "untupled".
+	pub fn call_tuple(mut self, args: Args) -> Result {
+		(*self.func)(args)
+	}
+
+	/// `SendBoxFnOnce::new` is an alias for `SendBoxFnOnce::from`.
+	pub fn new<F>(func: F) -> Self
+		where Self: From<F>
+	{
+		Self::from(func)
+	}
+}
+
+// implementation for zero arguments
+impl<Result> SendBoxFnOnce<(), Result> {
+	/**
+	 * call inner function, consumes the box
+	 */
+	pub fn call(mut self) -> Result {
+		(*self.func)(())
+	}
+}
+
+impl<Result, F: 'static + FnOnce() -> Result + Send> From<F> for SendBoxFnOnce<(), Result> {
+	fn from(func: F) -> Self {
+		let mut func = Some(func);
+		SendBoxFnOnce{
+			func: Box::new(move |_| -> Result {
+				func.take().unwrap()()
+			})
+		}
+	}
+}
+
+build_n_args_once!(SendBoxFnOnce [+ Send]: a1: A1);
+build_n_args_once!(SendBoxFnOnce [+ Send]: a1: A1, a2: A2);
+build_n_args_once!(SendBoxFnOnce [+ Send]: a1: A1, a2: A2, a3: A3);
+build_n_args_once!(SendBoxFnOnce [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4);
+build_n_args_once!(SendBoxFnOnce [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5);
+build_n_args_once!(SendBoxFnOnce [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6);
+build_n_args_once!(SendBoxFnOnce [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7);
+build_n_args_once!(SendBoxFnOnce [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8);
+build_n_args_once!(SendBoxFnOnce [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9);
+build_n_args_once!(SendBoxFnOnce [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9, a10: A10);
+
+/// Generalizes over every arity of `BoxFnOnce`/`SendBoxFnOnce`, so
+/// generic code can accept "some boxed one-shot callable with argument
+/// tuple `Args` returning `Output`" without committing to one
+/// concrete arity or to the `Send` bound.
+///
+/// `Args` is already a tuple for every arity (see the crate-level
+/// docs), so a single impl per box type covers all of them, the same
+/// way the inherent `call_tuple` methods above do.
+pub trait CallOnce<Args> {
+	/// the return type of the boxed function
+	type Output;
+
+	/// call inner function, consumes the box
+	fn call_once_tuple(self, args: Args) -> Self::Output;
+}
+
+impl<Args, Result> CallOnce<Args> for BoxFnOnce<Args, Result> {
+	type Output = Result;
+
+	fn call_once_tuple(self, args: Args) -> Result {
+		self.call_tuple(args)
+	}
+}
+
+impl<Args, Result> CallOnce<Args> for SendBoxFnOnce<Args, Result> {
+	type Output = Result;
+
+	fn call_once_tuple(self, args: Args) -> Result {
+		self.call_tuple(args)
+	}
+}
+
+/// `BoxFnMut` boxes any `FnMut` function up to a certain number of
+/// arguments (10 as of now), mirroring `BoxFnOnce` but allowing the
+/// boxed function to be called more than once.
+///
+/// See `BoxFnOnce` for the general design (tupled arguments, same
+/// arity limit); the difference is that `call`/`call_tuple` borrow
+/// `&mut self` instead of consuming the box.
+///
+/// # Examples
+///
+/// ```
+/// use boxfnonce::BoxFnMut;
+/// let mut f : BoxFnMut<(), usize> = BoxFnMut::from({
+///     let mut count = 0;
+///     move || {
+///         count += 1;
+///         count
+///     }
+/// });
+/// assert_eq!(f.call(), 1);
+/// assert_eq!(f.call(), 2);
+/// ```
+pub struct BoxFnMut<Args, Result = ()> {
+	func: Box<FnMut(Args) -> Result>,
+}
+
+impl<Args, Result> BoxFnMut<Args, Result> {
+	/// call inner function, can be called multiple times.
+	///
+	/// `call_tuple` can be used if the arguments are available as tuple.
+	/// Each usable instance of BoxFnMut<(...), Result> has a separate
+	/// `call` method for passing arguments "untupled".
+	pub fn call_tuple(&mut self, args: Args) -> Result {
+		(*self.func)(args)
+	}
+
+	/// `BoxFnMut::new` is an alias for `BoxFnMut::from`.
+	pub fn new<F>(func: F) -> Self
+		where Self: From<F>
+	{
+		Self::from(func)
+	}
+}
+
+// implementation for zero arguments
+impl<Result> BoxFnMut<(), Result> {
+	/**
+	 * call inner function, can be called multiple times
+	 */
+	pub fn call(&mut self) -> Result {
+		(*self.func)(())
+	}
+}
+
+impl<Result, F: 'static + FnMut() -> Result> From<F> for BoxFnMut<(), Result> {
+	fn from(mut func: F) -> Self {
+		BoxFnMut{
+			func: Box::new(move |_| -> Result {
+				func()
+			})
+		}
+	}
+}
+
+build_n_args_mut!(BoxFnMut []: a1: A1);
+build_n_args_mut!(BoxFnMut []: a1: A1, a2: A2);
+build_n_args_mut!(BoxFnMut []: a1: A1, a2: A2, a3: A3);
+build_n_args_mut!(BoxFnMut []: a1: A1, a2: A2, a3: A3, a4: A4);
+build_n_args_mut!(BoxFnMut []: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5);
+build_n_args_mut!(BoxFnMut []: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6);
+build_n_args_mut!(BoxFnMut []: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7);
+build_n_args_mut!(BoxFnMut []: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8);
+build_n_args_mut!(BoxFnMut []: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9);
+build_n_args_mut!(BoxFnMut []: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9, a10: A10);
+
+/// Like `BoxFnMut`, but the boxed closure is also required to be
+/// `Send`, so the whole box can be sent across threads.
+pub struct SendBoxFnMut<Args, Result = ()> {
+	func: Box<FnMut(Args) -> Result + Send>,
+}
+
+impl<Args, Result> SendBoxFnMut<Args, Result> {
+	/// call inner function, can be called multiple times.
+	///
+	/// `call_tuple` can be used if the arguments are available as tuple.
+	/// Each usable instance of SendBoxFnMut<(...), Result> has a separate
+	/// `call` method for passing arguments "untupled".
+	pub fn call_tuple(&mut self, args: Args) -> Result {
+		(*self.func)(args)
+	}
+
+	/// `SendBoxFnMut::new` is an alias for `SendBoxFnMut::from`.
+	pub fn new<F>(func: F) -> Self
+		where Self: From<F>
+	{
+		Self::from(func)
+	}
+}
+
+// implementation for zero arguments
+impl<Result> SendBoxFnMut<(), Result> {
+	/**
+	 * call inner function, can be called multiple times
+	 */
+	pub fn call(&mut self) -> Result {
+		(*self.func)(())
+	}
+}
+
+impl<Result, F: 'static + FnMut() -> Result + Send> From<F> for SendBoxFnMut<(), Result> {
+	fn from(mut func: F) -> Self {
+		SendBoxFnMut{
+			func: Box::new(move |_| -> Result {
+				func()
+			})
+		}
+	}
+}
+
+build_n_args_mut!(SendBoxFnMut [+ Send]: a1: A1);
+build_n_args_mut!(SendBoxFnMut [+ Send]: a1: A1, a2: A2);
+build_n_args_mut!(SendBoxFnMut [+ Send]: a1: A1, a2: A2, a3: A3);
+build_n_args_mut!(SendBoxFnMut [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4);
+build_n_args_mut!(SendBoxFnMut [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5);
+build_n_args_mut!(SendBoxFnMut [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6);
+build_n_args_mut!(SendBoxFnMut [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7);
+build_n_args_mut!(SendBoxFnMut [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8);
+build_n_args_mut!(SendBoxFnMut [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9);
+build_n_args_mut!(SendBoxFnMut [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9, a10: A10);
+
+/// `BoxFn` boxes any `Fn` function up to a certain number of
+/// arguments (10 as of now); the counterpart to `BoxFnMut` for
+/// closures that don't need to mutate their captured state, so `call`
+/// only needs `&self`.
+pub struct BoxFn<Args, Result = ()> {
+	func: Box<Fn(Args) -> Result>,
+}
+
+impl<Args, Result> BoxFn<Args, Result> {
+	/// call inner function, can be called multiple times.
+	///
+	/// `call_tuple` can be used if the arguments are available as tuple.
+	/// Each usable instance of BoxFn<(...), Result> has a separate
+	/// `call` method for passing arguments "untupled".
+	pub fn call_tuple(&self, args: Args) -> Result {
+		(self.func)(args)
+	}
+
+	/// `BoxFn::new` is an alias for `BoxFn::from`.
+	pub fn new<F>(func: F) -> Self
+		where Self: From<F>
+	{
+		Self::from(func)
+	}
+}
+
+// implementation for zero arguments
+impl<Result> BoxFn<(), Result> {
+	/**
+	 * call inner function, can be called multiple times
+	 */
+	pub fn call(&self) -> Result {
+		(self.func)(())
+	}
+}
+
+impl<Result, F: 'static + Fn() -> Result> From<F> for BoxFn<(), Result> {
+	fn from(func: F) -> Self {
+		BoxFn{
+			func: Box::new(move |_| -> Result {
+				func()
+			})
+		}
+	}
+}
+
+build_n_args_fn!(BoxFn []: a1: A1);
+build_n_args_fn!(BoxFn []: a1: A1, a2: A2);
+build_n_args_fn!(BoxFn []: a1: A1, a2: A2, a3: A3);
+build_n_args_fn!(BoxFn []: a1: A1, a2: A2, a3: A3, a4: A4);
+build_n_args_fn!(BoxFn []: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5);
+build_n_args_fn!(BoxFn []: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6);
+build_n_args_fn!(BoxFn []: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7);
+build_n_args_fn!(BoxFn []: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8);
+build_n_args_fn!(BoxFn []: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9);
+build_n_args_fn!(BoxFn []: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9, a10: A10);
+
+/// Like `BoxFn`, but the boxed closure is also required to be `Send`,
+/// so the whole box can be sent across threads.
+pub struct SendBoxFn<Args, Result = ()> {
+	func: Box<Fn(Args) -> Result + Send>,
+}
+
+impl<Args, Result> SendBoxFn<Args, Result> {
+	/// call inner function, can be called multiple times.
+	///
+	/// `call_tuple` can be used if the arguments are available as tuple.
+	/// Each usable instance of SendBoxFn<(...), Result> has a separate
+	/// `call` method for passing arguments "untupled".
+	pub fn call_tuple(&self, args: Args) -> Result {
+		(self.func)(args)
+	}
+
+	/// `SendBoxFn::new` is an alias for `SendBoxFn::from`.
+	pub fn new<F>(func: F) -> Self
+		where Self: From<F>
+	{
+		Self::from(func)
+	}
+}
+
+// implementation for zero arguments
+impl<Result> SendBoxFn<(), Result> {
+	/**
+	 * call inner function, can be called multiple times
+	 */
+	pub fn call(&self) -> Result {
+		(self.func)(())
+	}
+}
+
+impl<Result, F: 'static + Fn() -> Result + Send> From<F> for SendBoxFn<(), Result> {
+	fn from(func: F) -> Self {
+		SendBoxFn{
+			func: Box::new(move |_| -> Result {
+				func()
+			})
+		}
+	}
+}
+
+build_n_args_fn!(SendBoxFn [+ Send]: a1: A1);
+build_n_args_fn!(SendBoxFn [+ Send]: a1: A1, a2: A2);
+build_n_args_fn!(SendBoxFn [+ Send]: a1: A1, a2: A2, a3: A3);
+build_n_args_fn!(SendBoxFn [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4);
+build_n_args_fn!(SendBoxFn [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5);
+build_n_args_fn!(SendBoxFn [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6);
+build_n_args_fn!(SendBoxFn [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7);
+build_n_args_fn!(SendBoxFn [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8);
+build_n_args_fn!(SendBoxFn [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9);
+build_n_args_fn!(SendBoxFn [+ Send]: a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9, a10: A10);
+
+// the suite below uses std-only items (String, ::std::thread) directly,
+// so it only builds/runs with the (default-on) std feature enabled
+#[cfg(all(test, feature = "std"))]
 mod test {
-	use super::BoxFnOnce;
+	use super::{BoxFnOnce, SendBoxFnOnce, BoxFnMut, SendBoxFnMut, BoxFn, SendBoxFn, CallOnce};
 
 	#[derive(PartialEq,Eq,Debug)]
 	struct Arg1{}
@@ -149,24 +601,28 @@ mod test {
 	#[derive(PartialEq,Eq,Debug)]
 	struct Arg2{}
 
+	#[cfg(not(feature = "unboxed_closures"))]
 	#[derive(PartialEq,Eq,Debug)]
 	struct Arg3{}
 
+	#[cfg(not(feature = "unboxed_closures"))]
 	#[derive(PartialEq,Eq,Debug)]
 	struct Arg4{}
 
+	#[cfg(not(feature = "unboxed_closures"))]
 	#[test]
 	fn test_arg0() {
 		let f = {
 			let s = String::from("abc");
 			move || -> String {
-				(s)
+				s
 			}
 		};
 		let f = BoxFnOnce::from(f);
 		assert_eq!(f.call(), "abc".to_string());
 	}
 
+	#[cfg(not(feature = "unboxed_closures"))]
 	#[test]
 	fn test_arg1() {
 		let f = {
@@ -179,6 +635,7 @@ mod test {
 		assert_eq!(f.call(Arg1{}), ("abc".into(), Arg1{}));
 	}
 
+	#[cfg(not(feature = "unboxed_closures"))]
 	#[test]
 	fn test_arg2() {
 		let f = {
@@ -191,6 +648,7 @@ mod test {
 		assert_eq!(f.call(Arg1{}, Arg2{}), ("abc".into(), Arg1{}, Arg2{}));
 	}
 
+	#[cfg(not(feature = "unboxed_closures"))]
 	#[test]
 	fn test_arg3() {
 		let f = {
@@ -203,6 +661,7 @@ mod test {
 		assert_eq!(f.call(Arg1{}, Arg2{}, Arg3{}), ("abc".into(), Arg1{}, Arg2{}, Arg3{}));
 	}
 
+	#[cfg(not(feature = "unboxed_closures"))]
 	#[test]
 	fn test_arg4_void() {
 		let f = {
@@ -219,6 +678,7 @@ mod test {
 		f.call(Arg1{}, Arg2{}, Arg3{}, Arg4{});
 	}
 
+	#[cfg(not(feature = "unboxed_closures"))]
 	#[test]
 	#[should_panic(expected = "inner diverging")]
 	fn test_arg4_diverging() {
@@ -236,4 +696,108 @@ mod test {
 		let f = BoxFnOnce::from(f);
 		f.call(Arg1{}, Arg2{}, Arg3{}, Arg4{});
 	}
+
+	#[cfg(not(feature = "unboxed_closures"))]
+	#[test]
+	fn test_from_boxed_fnonce() {
+		let s = String::from("abc");
+		let boxed: Box<FnOnce() -> String> = Box::new(move || s);
+		let f = BoxFnOnce::from(boxed);
+		assert_eq!(f.call(), "abc".to_string());
+	}
+
+	#[cfg(not(feature = "unboxed_closures"))]
+	#[test]
+	fn test_into_boxed() {
+		let s = String::from("abc");
+		let f : BoxFnOnce<(), String> = BoxFnOnce::from(move || s);
+		let boxed: Box<FnOnce() -> String> = f.into_boxed();
+		assert_eq!(boxed(), "abc".to_string());
+	}
+
+	#[cfg(feature = "unboxed_closures")]
+	#[test]
+	fn test_unboxed_closures() {
+		let f = {
+			let s = String::from("abc");
+			move |a: Arg1, b: Arg2| -> (String, Arg1, Arg2) {
+				(s, a, b)
+			}
+		};
+		let f = BoxFnOnce::<(Arg1, Arg2), (String, Arg1, Arg2)>::from_fn(f);
+		assert_eq!(f(Arg1{}, Arg2{}), ("abc".into(), Arg1{}, Arg2{}));
+	}
+
+	#[test]
+	fn test_box_fn_mut() {
+		let mut count = 0;
+		let mut f : BoxFnMut<(Arg1,), usize> = BoxFnMut::from(move |_a| {
+			count += 1;
+			count
+		});
+		assert_eq!(f.call(Arg1{}), 1);
+		assert_eq!(f.call(Arg1{}), 2);
+	}
+
+	#[test]
+	fn test_box_fn() {
+		let s = String::from("abc");
+		let f : BoxFn<(Arg1,), (String, Arg1)> = BoxFn::from(move |a| (s.clone(), a));
+		assert_eq!(f.call(Arg1{}), ("abc".into(), Arg1{}));
+		assert_eq!(f.call(Arg1{}), ("abc".into(), Arg1{}));
+	}
+
+	#[test]
+	fn test_send_box_fn() {
+		let s = String::from("abc");
+		let f : SendBoxFn<(Arg1,), (String, Arg1)> = SendBoxFn::from(move |a| (s.clone(), a));
+		let handle = ::std::thread::spawn(move || {
+			assert_eq!(f.call(Arg1{}), ("abc".into(), Arg1{}));
+			assert_eq!(f.call(Arg1{}), ("abc".into(), Arg1{}));
+		});
+		handle.join().unwrap();
+	}
+
+	#[test]
+	fn test_send_box_fn_mut() {
+		let mut f : SendBoxFnMut<(), usize> = SendBoxFnMut::from({
+			let mut count = 0;
+			move || {
+				count += 1;
+				count
+			}
+		});
+		let handle = ::std::thread::spawn(move || {
+			assert_eq!(f.call(), 1);
+			assert_eq!(f.call(), 2);
+		});
+		handle.join().unwrap();
+	}
+
+	#[test]
+	fn test_send_box_fn_once() {
+		let s = String::from("abc");
+		let f : SendBoxFnOnce<(Arg1,), (String, Arg1)> = SendBoxFnOnce::from(move |a| (s, a));
+		let handle = ::std::thread::spawn(move || {
+			assert_eq!(f.call(Arg1{}), ("abc".into(), Arg1{}));
+		});
+		handle.join().unwrap();
+	}
+
+	#[cfg(not(feature = "unboxed_closures"))]
+	fn call_it<T: CallOnce<(Arg1,), Output = (String, Arg1)>>(f: T) -> (String, Arg1) {
+		f.call_once_tuple((Arg1{},))
+	}
+
+	#[cfg(not(feature = "unboxed_closures"))]
+	#[test]
+	fn test_call_once_trait() {
+		let s = String::from("abc");
+		let f : BoxFnOnce<(Arg1,), (String, Arg1)> = BoxFnOnce::from(move |a| (s, a));
+		assert_eq!(call_it(f), ("abc".into(), Arg1{}));
+
+		let s = String::from("abc");
+		let f : SendBoxFnOnce<(Arg1,), (String, Arg1)> = SendBoxFnOnce::from(move |a| (s, a));
+		assert_eq!(call_it(f), ("abc".into(), Arg1{}));
+	}
 }
@@ -1,6 +1,9 @@
 #![warn(missing_docs)]
 
-macro_rules! build_n_args {
+/// Like the (arity-specific, `BoxFnOnce`-only) `build_n_args!` macro
+/// in `lib.rs`, but parameterized over `$name` and the `$add` bound hook, so
+/// it can build a `Send` sibling too. Used for `SendBoxFnOnce`.
+macro_rules! build_n_args_once {
 	( $name:ident [$($add:tt)*]: $($var:ident: $typevar:ident),* ) => (
 		impl< $($typevar,)* Result> $name<($($typevar,)*), Result> {
 			/**
@@ -31,3 +34,57 @@ macro_rules! build_n_args {
 		}
 	)
 }
+
+/// Like `build_n_args!`, but for boxed closures that can be called
+/// more than once: `call`/`call_tuple` borrow `&mut self` instead of
+/// consuming the box, and the inner `FnMut` is stored directly instead
+/// of behind an `Option`. Used for `BoxFnMut` and (via the `$add` hook)
+/// `SendBoxFnMut`.
+macro_rules! build_n_args_mut {
+	( $name:ident [$($add:tt)*]: $($var:ident: $typevar:ident),* ) => (
+		impl< $($typevar,)* Result> $name<($($typevar,)*), Result> {
+			/**
+			 * call inner function, can be called multiple times
+			 */
+			pub fn call(&mut self $(, $var: $typevar)*) -> Result {
+				(*self.func)(($($var ,)*))
+			}
+		}
+
+		impl< $($typevar,)* Result, F: 'static + FnMut($($typevar),*) -> Result $($add)*> From<F> for $name<($($typevar,)*), Result> {
+			fn from(mut func: F) -> Self {
+				$name{
+					func: Box::new(move |($($var ,)*)| -> Result {
+						func($($var),*)
+					})
+				}
+			}
+		}
+	)
+}
+
+/// Like `build_n_args_mut!`, but for boxed closures that only need
+/// `&self`: used for `BoxFn` and (via the `$add` hook) `SendBoxFn`.
+macro_rules! build_n_args_fn {
+	( $name:ident [$($add:tt)*]: $($var:ident: $typevar:ident),* ) => (
+		impl< $($typevar,)* Result> $name<($($typevar,)*), Result> {
+			/**
+			 * call inner function, can be called multiple times
+			 */
+			pub fn call(&self $(, $var: $typevar)*) -> Result {
+				(self.func)(($($var ,)*))
+			}
+		}
+
+		impl< $($typevar,)* Result, F: 'static + Fn($($typevar),*) -> Result $($add)*> From<F> for $name<($($typevar,)*), Result> {
+			fn from(func: F) -> Self {
+				$name{
+					func: Box::new(move |($($var ,)*)| -> Result {
+						func($($var),*)
+					})
+				}
+			}
+		}
+	)
+}
+